@@ -1,16 +1,26 @@
 #!/usr/bin/env rust-script
 //! Async script using tokio runtime.
 //!
+//! Demonstrates resilient concurrent fetching: each request goes through
+//! `fetch_with_retry`, which applies a per-request timeout and retries
+//! transient failures with exponential backoff and jitter.
+//!
 //! ```cargo
 //! [dependencies]
 //! tokio = { version = "1", features = ["full"] }
-//! reqwest = "0.11"
+//! reqwest = { version = "0.11", features = ["json"] }
 //! serde = { version = "1.0", features = ["derive"] }
 //! serde_json = "1.0"
+//! rand = "0.8"
 //! ```
 //!
 //! Run: rust-script async.rs
 
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -27,10 +37,12 @@ struct UuidResponse {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Fetching data asynchronously...\n");
 
+    let client = Client::new();
+
     // Run multiple requests concurrently
     let (ip_result, uuid_result) = tokio::join!(
-        fetch_ip(),
-        fetch_uuid()
+        fetch_ip(&client),
+        fetch_uuid(&client)
     );
 
     match ip_result {
@@ -47,16 +59,53 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-async fn fetch_ip() -> Result<IpResponse, reqwest::Error> {
-    reqwest::get("https://httpbin.org/ip")
-        .await?
-        .json()
-        .await
+async fn fetch_ip(client: &Client) -> Result<IpResponse, Box<dyn std::error::Error>> {
+    fetch_with_retry(client, "https://httpbin.org/ip", 3).await
 }
 
-async fn fetch_uuid() -> Result<UuidResponse, reqwest::Error> {
-    reqwest::get("https://httpbin.org/uuid")
-        .await?
-        .json()
-        .await
+async fn fetch_uuid(client: &Client) -> Result<UuidResponse, Box<dyn std::error::Error>> {
+    fetch_with_retry(client, "https://httpbin.org/uuid", 3).await
+}
+
+/// Fetches and deserializes `url`, retrying transient failures.
+///
+/// Each attempt gets a 10s timeout. Connection errors, 429, and 5xx
+/// responses are retried with exponential backoff (`base * 2^attempt`,
+/// capped at a ceiling) plus a small random jitter to avoid thundering
+/// herds; any other 4xx response fails immediately.
+async fn fetch_with_retry<T: DeserializeOwned>(
+    client: &Client,
+    url: &str,
+    max_retries: u32,
+) -> Result<T, Box<dyn std::error::Error>> {
+    const BASE_DELAY: Duration = Duration::from_millis(200);
+    const MAX_DELAY: Duration = Duration::from_secs(5);
+
+    let mut attempt = 0;
+
+    loop {
+        let result = client.get(url).timeout(Duration::from_secs(10)).send().await;
+
+        let should_retry = match &result {
+            Ok(resp) => {
+                let status = resp.status();
+                status.is_server_error() || status.as_u16() == 429
+            }
+            Err(e) => !e.is_builder() && !e.is_redirect(),
+        };
+
+        if !should_retry {
+            return Ok(result?.error_for_status()?.json().await?);
+        }
+
+        if attempt >= max_retries {
+            return Ok(result?.error_for_status()?.json().await?);
+        }
+
+        let backoff = BASE_DELAY.saturating_mul(1 << attempt.min(31)).min(MAX_DELAY);
+        let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=backoff.as_millis() as u64 / 4 + 1));
+        tokio::time::sleep(backoff + jitter).await;
+
+        attempt += 1;
+    }
 }
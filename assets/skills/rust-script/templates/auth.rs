@@ -0,0 +1,65 @@
+#!/usr/bin/env rust-script
+//! Async script building an authenticated reqwest client.
+//!
+//! Reads a bearer token from the environment and bakes it into every
+//! request via `ClientBuilder::default_headers`, so the client can be
+//! reused across multiple authenticated endpoints instead of anonymous
+//! one-off `reqwest::get` calls.
+//!
+//! ```cargo
+//! [dependencies]
+//! tokio = { version = "1", features = ["full"] }
+//! reqwest = { version = "0.11", features = ["json"] }
+//! serde = { version = "1.0", features = ["derive"] }
+//! serde_json = "1.0"
+//! ```
+//!
+//! Run: API_TOKEN=... rust-script auth.rs
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use reqwest::Client;
+
+const BASE_URL: &str = "https://httpbin.org";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = build_client()?;
+
+    let bearer: serde_json::Value = client
+        .get(format!("{}/bearer", BASE_URL))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    println!("{}", serde_json::to_string_pretty(&bearer)?);
+
+    // Reuse the same client for another authenticated endpoint -- the
+    // Authorization and User-Agent headers are sent automatically.
+    let headers: serde_json::Value = client
+        .get(format!("{}/headers", BASE_URL))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    println!("{}", serde_json::to_string_pretty(&headers)?);
+
+    Ok(())
+}
+
+fn build_client() -> Result<Client, Box<dyn std::error::Error>> {
+    let token = std::env::var("API_TOKEN")
+        .map_err(|_| "API_TOKEN environment variable is not set")?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", token))?,
+    );
+    headers.insert(USER_AGENT, HeaderValue::from_static("rust-script-example/1.0"));
+
+    Ok(Client::builder().default_headers(headers).build()?)
+}
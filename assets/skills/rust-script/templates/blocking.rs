@@ -0,0 +1,56 @@
+#!/usr/bin/env rust-script
+//! Synchronous (blocking) script using reqwest's blocking client.
+//!
+//! No tokio runtime is needed here: the `blocking` feature pulls in its own
+//! internal runtime under the hood, which is a good trade-off for short-lived
+//! CLI scripts that only ever make a couple of sequential requests. Reach for
+//! `async.rs` instead once you need to run many requests concurrently.
+//!
+//! ```cargo
+//! [dependencies]
+//! reqwest = { version = "0.11", features = ["blocking", "json"] }
+//! serde = { version = "1.0", features = ["derive"] }
+//! serde_json = "1.0"
+//! ```
+//!
+//! Run: rust-script blocking.rs
+
+use reqwest::blocking::Client;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct IpResponse {
+    origin: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UuidResponse {
+    uuid: String,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Fetching data synchronously...\n");
+
+    let client = Client::new();
+
+    match fetch_ip(&client) {
+        Ok(ip) => println!("Your IP: {}", ip.origin),
+        Err(e) => eprintln!("Failed to fetch IP: {}", e),
+    }
+
+    match fetch_uuid(&client) {
+        Ok(uuid) => println!("Random UUID: {}", uuid.uuid),
+        Err(e) => eprintln!("Failed to fetch UUID: {}", e),
+    }
+
+    println!("\nDone!");
+    Ok(())
+}
+
+fn fetch_ip(client: &Client) -> Result<IpResponse, reqwest::Error> {
+    client.get("https://httpbin.org/ip").send()?.json()
+}
+
+fn fetch_uuid(client: &Client) -> Result<UuidResponse, reqwest::Error> {
+    client.get("https://httpbin.org/uuid").send()?.json()
+}
@@ -0,0 +1,43 @@
+#!/usr/bin/env rust-script
+//! Script comparing MessagePack and JSON encoding of the same struct.
+//!
+//! MessagePack is a compact binary format: the same `Serialize`/`Deserialize`
+//! derives used for JSON work unchanged with `rmp-serde`, so swapping wire
+//! formats is just a matter of which `to_`/`from_` functions you call.
+//!
+//! ```cargo
+//! [dependencies]
+//! serde = { version = "1.0", features = ["derive"] }
+//! serde_json = "1.0"
+//! rmp-serde = "1.1"
+//! chrono = "0.4"
+//! ```
+//!
+//! Run: rust-script msgpack.rs
+
+use serde::{Deserialize, Serialize};
+use chrono::Local;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Message {
+    text: String,
+    timestamp: String,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let msg = Message {
+        text: "Hello from rust-script!".to_string(),
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    };
+
+    let json = serde_json::to_string(&msg)?;
+    let packed = rmp_serde::to_vec(&msg)?;
+
+    println!("JSON:       {} bytes", json.len());
+    println!("MessagePack: {} bytes", packed.len());
+
+    let roundtripped: Message = rmp_serde::from_slice(&packed)?;
+    println!("\nRound-tripped: {:?}", roundtripped);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+#!/usr/bin/env rust-script
+//! Async script demonstrating uploads: multipart form data and a streamed
+//! file body.
+//!
+//! `reqwest::Body` no longer implements `From<std::fs::File>` in 0.11, so the
+//! streaming variant wraps a `tokio::fs::File` in a `FramedRead` and turns
+//! the resulting stream of byte chunks into a `Body` via `wrap_stream`.
+//!
+//! ```cargo
+//! [dependencies]
+//! tokio = { version = "1", features = ["full"] }
+//! tokio-util = { version = "0.7", features = ["codec"] }
+//! reqwest = { version = "0.11", features = ["json", "multipart", "stream"] }
+//! serde = { version = "1.0", features = ["derive"] }
+//! serde_json = "1.0"
+//! ```
+//!
+//! Run: rust-script upload.rs
+
+use reqwest::{multipart, Body, Client};
+use serde::Deserialize;
+use std::path::Path;
+use tokio::fs::File;
+use tokio_util::codec::{BytesCodec, FramedRead};
+
+#[derive(Debug, Deserialize)]
+struct PostResponse {
+    files: serde_json::Value,
+    form: serde_json::Value,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = "/etc/hostname";
+    let client = Client::new();
+
+    println!("Uploading {} as multipart form data...\n", path);
+    upload_multipart(&client, path).await?;
+
+    println!("\nUploading {} as a streamed request body...\n", path);
+    upload_streamed(&client, path).await?;
+
+    Ok(())
+}
+
+async fn upload_multipart(client: &Client, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    // `multipart::Form::file` only exists on the blocking client; the async
+    // equivalent is to stream the file into a `Part` ourselves.
+    let file_name = Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("file")
+        .to_string();
+
+    let file = File::open(path).await?;
+    let stream = FramedRead::new(file, BytesCodec::new());
+    let part = multipart::Part::stream(Body::wrap_stream(stream)).file_name(file_name);
+
+    let form = multipart::Form::new()
+        .text("description", "uploaded via rust-script")
+        .part("file", part);
+
+    let resp: PostResponse = client
+        .post("https://httpbin.org/post")
+        .multipart(form)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    println!("Echoed files: {}", resp.files);
+    println!("Echoed form fields: {}", resp.form);
+    Ok(())
+}
+
+async fn upload_streamed(client: &Client, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::open(path).await?;
+    let stream = FramedRead::new(file, BytesCodec::new());
+    let body = Body::wrap_stream(stream);
+
+    let resp: serde_json::Value = client
+        .post("https://httpbin.org/post")
+        .body(body)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    println!("Server received {} bytes", resp["data"].as_str().unwrap_or_default().len());
+    Ok(())
+}
@@ -0,0 +1,64 @@
+#!/usr/bin/env rust-script
+//! Async script fetching and deserializing an XML API response.
+//!
+//! `quick-xml`'s serde support maps attributes via `#[serde(rename = "@name")]`
+//! and child elements as plain fields, mirroring how `serde_json` maps JSON
+//! keys. Setting `Accept: application/xml` shows how to negotiate content
+//! type with APIs that can return either XML or JSON.
+//!
+//! ```cargo
+//! [dependencies]
+//! tokio = { version = "1", features = ["full"] }
+//! reqwest = "0.11"
+//! serde = { version = "1.0", features = ["derive"] }
+//! quick-xml = { version = "0.31", features = ["serialize"] }
+//! ```
+//!
+//! Run: rust-script xml.rs
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Slideshow {
+    #[serde(rename = "@title")]
+    title: String,
+    #[serde(rename = "@date")]
+    date: String,
+    #[serde(rename = "slide")]
+    slides: Vec<Slide>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Slide {
+    #[serde(rename = "@type")]
+    kind: String,
+    title: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Fetching XML data...\n");
+
+    let slideshow = fetch_slideshow().await?;
+
+    println!("Title: {} ({})", slideshow.title, slideshow.date);
+    for slide in &slideshow.slides {
+        println!("- [{}] {}", slide.kind, slide.title);
+    }
+
+    Ok(())
+}
+
+async fn fetch_slideshow() -> Result<Slideshow, Box<dyn std::error::Error>> {
+    let client = reqwest::Client::new();
+
+    let body = client
+        .get("https://httpbin.org/xml")
+        .header("Accept", "application/xml")
+        .send()
+        .await?
+        .text()
+        .await?;
+
+    Ok(quick_xml::de::from_str(&body)?)
+}